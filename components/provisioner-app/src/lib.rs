@@ -6,6 +6,8 @@
 //! attestation keys.
 //! It allows generating Trussed device attestation keys and obtaining their public keys,
 //! to then generate and inject attn certs from a given root or intermedidate CA.
+//! It allows reading back the installed FIDO batch attestation certificate and public key,
+//! so a test station can confirm provisioning without a full make_credential.
 //!
 //! See `solo2-cli` for usage.
 #![no_std]
@@ -58,6 +60,9 @@ pub enum Instructions {
 
     SaveT1IntermediatePublicKey = 0xb5,
 
+    GetFidoAttestationCertificate = 0xb4,
+    GetFidoAttestationPublicKey = 0xb3,
+
     #[cfg(feature = "test-attestation")]
     TestAttestation = 0xb8,
 }
@@ -83,6 +88,9 @@ impl TryFrom<u8> for Instructions {
 
             0xb5 => SaveT1IntermediatePublicKey,
 
+            0xb4 => GetFidoAttestationCertificate,
+            0xb3 => GetFidoAttestationPublicKey,
+
             #[cfg(feature = "test-attestation")]
             0xb8 => TestAttestation,
             _ => return Err(()),
@@ -112,6 +120,10 @@ const FILENAME_P256_CERT: &'static [u8] = b"/attn/x5c/01";
 const FILENAME_ED255_CERT: &'static [u8] = b"/attn/x5c/02";
 const FILENAME_X255_CERT: &'static [u8] = b"/attn/x5c/03";
 
+// FIDO batch attestation, as injected via `WriteFile` into the fido client's namespace
+const FILENAME_FIDO_SECRET: &'static [u8] = b"/fido/sec/00";
+const FILENAME_FIDO_CERT: &'static [u8] = b"/fido/x5c/00";
+
 
 
 enum SelectedBuffer {
@@ -383,6 +395,35 @@ where S: Store,
                             }
                         },
 
+                        GetFidoAttestationCertificate => {
+                            // No certificate means the FIDO app is in self-signed mode.
+                            let cert: trussed::types::Message = store::read(self.store,
+                                trussed::types::Location::Internal,
+                                &PathBuf::from(FILENAME_FIDO_CERT),
+                            ).map_err(|_| Status::NotFound)?;
+                            reply.extend_from_slice(&cert).map_err(|_| Status::NotEnoughMemory)?;
+                            Ok(())
+                        },
+
+                        GetFidoAttestationPublicKey => {
+                            let serialized_key: trussed::types::Message = store::read(self.store,
+                                trussed::types::Location::Internal,
+                                &PathBuf::from(FILENAME_FIDO_SECRET),
+                            ).map_err(|_| Status::NotFound)?;
+                            let key = Key::try_deserialize(&serialized_key)
+                                .map_err(|_| Status::IncorrectDataParameter)?;
+                            if key.kind != KeyKind::P256 || key.material.len() != 32 {
+                                return Err(Status::IncorrectDataParameter);
+                            }
+
+                            let mut seed = [0u8; 32];
+                            seed.copy_from_slice(&key.material);
+                            let keypair = nisty::Keypair::generate_patiently(&seed);
+
+                            reply.extend_from_slice(keypair.public.as_bytes()).unwrap();
+                            Ok(())
+                        },
+
                         #[cfg(feature = "test-attestation")]
                         TestAttestation => {
                             // This is only exposed for development and testing.
@@ -515,10 +515,13 @@ impl Initializer {
             // So for instance "Hacker Solo 2" would work, but "Solo 2 (custom)" would not.
             let ccid = usbd_ccid::Ccid::new(usb_bus, contact_requester, Some(b"Solo 2"));
             let current_time = basic_stage.perf_timer.elapsed().0/1000;
-            let mut ctaphid = usbd_ctaphid::CtapHid::new(usb_bus, ctaphid_requester, current_time)
-                .implements_ctap1()
+            let ctaphid = usbd_ctaphid::CtapHid::new(usb_bus, ctaphid_requester, current_time)
                 .implements_ctap2()
                 .implements_wink();
+            // without U2F, keep the NMSG capability so platforms don't send CTAP1 messages
+            #[cfg(feature = "u2f")]
+            let ctaphid = ctaphid.implements_ctap1();
+            let mut ctaphid = ctaphid;
 
             ctaphid.set_version(usbd_ctaphid::Version {
                 major: crate::build_constants::CARGO_PKG_VERSION_MAJOR,